@@ -0,0 +1,114 @@
+use crate::Polynomial;
+
+/// A precomputed Barrett reducer for fast modular reduction by a fixed GF(2) polynomial.
+///
+/// [`Div`](core::ops::Div) on [`Polynomial`] reduces a value one shifted subtraction at a time,
+/// which costs one iteration per set bit of the quotient. A Barrett reducer instead precomputes a
+/// reciprocal constant `mu = x^(2k) / poly` once, where `k` is the degree of `poly`, and then
+/// reduces any value of up to `2k` bits using two fixed-cost carry-less multiplications plus a
+/// final correction, regardless of how large the quotient would have been.
+///
+/// `POLY_BYTES` is the width `poly` and the reduced remainder are stored in. `VALUE_BYTES` is the
+/// width of values passed to [`reduce`](Self::reduce); it must be wide enough to hold `2 * k` bits,
+/// which in practice means `VALUE_BYTES = 2 * POLY_BYTES`.
+///
+/// [`reduce`](Self::reduce) computes the exact polynomial remainder, i.e. the unique value of
+/// degree less than `k` congruent to `value` modulo `poly`, and always agrees with
+/// [`Div`](core::ops::Div) on the same inputs. Prefer this type when you control the generator
+/// and want predictable, `O(1)`-multiplication reduction regardless of the quotient's size; use
+/// `Div` directly for one-off reductions where precomputing `mu` isn't worth it.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::{BarrettReducer, Polynomial};
+///
+/// let poly = Polynomial([0x01, 0x1b]);
+/// let reducer = BarrettReducer::<2, 4>::new(poly);
+///
+/// let value = Polynomial([0x00, 0x00, 0xab, 0xcd]);
+/// let widened_poly: Polynomial<4> = Polynomial::new_from_bytes(poly.0);
+/// assert_eq!(reducer.reduce(value), (value / widened_poly).truncate());
+/// ```
+pub struct BarrettReducer<const POLY_BYTES: usize, const VALUE_BYTES: usize> {
+    poly: Polynomial<POLY_BYTES>,
+    mu: Polynomial<POLY_BYTES>,
+    k: usize,
+}
+
+impl<const POLY_BYTES: usize, const VALUE_BYTES: usize> BarrettReducer<POLY_BYTES, VALUE_BYTES> {
+    /// Precompute the reciprocal constant used to reduce values by `poly`.
+    ///
+    /// This runs a long division once, at construction time, so that
+    /// [`reduce`](Self::reduce) never has to.
+    pub fn new(poly: Polynomial<POLY_BYTES>) -> Self {
+        let k = poly.bit_len();
+
+        let widened_poly: Polynomial<VALUE_BYTES> = Polynomial::new_from_bytes(poly.0);
+
+        // `Div` yields the remainder, so divide `x^(2k)` by `poly` here while keeping the quotient.
+        let zero = Polynomial([0x00; VALUE_BYTES]);
+        let mut rem: Polynomial<VALUE_BYTES> = Polynomial::monomial(2 * k);
+        let mut quotient = zero;
+        while rem != zero && rem.bit_len() >= k {
+            let shift = rem.bit_len() - k;
+            rem = rem - (widened_poly << shift);
+            quotient = quotient - Polynomial::monomial(shift);
+        }
+        let mu = quotient.truncate();
+
+        Self { poly, mu, k }
+    }
+
+    /// Reduce `value` modulo the polynomial this reducer was built for.
+    ///
+    /// `value` is expected to be at most `2 * k` bits wide, `k` being the degree of the polynomial
+    /// passed to [`new`](Self::new); this is the range a single Barrett reduction step covers.
+    pub fn reduce(&self, value: Polynomial<VALUE_BYTES>) -> Polynomial<POLY_BYTES> {
+        let t1: Polynomial<POLY_BYTES> = (value >> self.k).truncate();
+        let t2: Polynomial<VALUE_BYTES> = t1.clmul(self.mu);
+        let q: Polynomial<POLY_BYTES> = (t2 >> self.k).truncate();
+        let q_poly: Polynomial<VALUE_BYTES> = q.clmul(self.poly);
+
+        let r: Polynomial<POLY_BYTES> = (value - q_poly).truncate();
+
+        // `mu` is a floored reciprocal, so `r` can still be one multiple of `poly` too big. Correct
+        // for that by dividing the already-small remainder by `poly`, which by now takes at most
+        // the single iteration of the "one conditional final subtraction" the technique is named for.
+        r / self.poly
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_div_for_hand_picked_vectors() {
+        let poly = Polynomial([0x01, 0x1b]);
+        let reducer = BarrettReducer::<2, 4>::new(poly);
+
+        let test_vectors: [[u8; 4]; 3] = [
+            [0x00, 0x00, 0xab, 0xcd],
+            [0x00, 0x00, 0x12, 0x34],
+            [0x00, 0x00, 0x00, 0x00],
+        ];
+
+        for value in test_vectors {
+            let value = Polynomial(value);
+            let widened_poly: Polynomial<4> = Polynomial::new_from_bytes(poly.0);
+            assert_eq!(reducer.reduce(value), (value / widened_poly).truncate());
+        }
+    }
+
+    #[test]
+    fn works_for_a_differently_shaped_generator() {
+        // `0xA6` with an explicit top bit, embedded at double width for the reducer.
+        let poly = Polynomial([0x01, 0xa6]);
+        let reducer = BarrettReducer::<2, 4>::new(poly);
+
+        let value = Polynomial([0x00, 0x00, 0xf0, 0x00]);
+        let widened_poly: Polynomial<4> = Polynomial::new_from_bytes(poly.0);
+        assert_eq!(reducer.reduce(value), (value / widened_poly).truncate());
+    }
+}
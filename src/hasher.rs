@@ -0,0 +1,154 @@
+use crate::table::Crc8Table;
+use crate::Crc8Configuration;
+
+/// A chunk-at-a-time source of bytes, modeled on the advance-through-chunks `Buf` pattern so
+/// non-contiguous buffers can be hashed without first copying them into one contiguous slice.
+pub trait Buf {
+    /// The number of bytes left to be consumed.
+    fn remaining(&self) -> usize;
+
+    /// The current contiguous chunk of the remaining bytes.
+    ///
+    /// May be shorter than [`remaining`](Buf::remaining) when the underlying data is split
+    /// across multiple chunks; call [`advance`](Buf::advance) and call this again to get the
+    /// next chunk.
+    fn chunk(&self) -> &[u8];
+
+    /// Advance the internal cursor by `cnt` bytes.
+    ///
+    /// `cnt` will never be more than the length of the last [`chunk`](Buf::chunk) returned.
+    fn advance(&mut self, cnt: usize);
+}
+
+impl Buf for &[u8] {
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self
+    }
+
+    fn advance(&mut self, cnt: usize) {
+        *self = &self[cnt..];
+    }
+}
+
+/// A streaming CRC-8 hasher that accepts data in arbitrarily sized chunks.
+///
+/// Unlike [`fetch_crc8`](crate::fetch_crc8), which requires the whole message upfront as one
+/// fixed-size `[u8; DATA_SIZE]` array, `Crc8Hasher` carries the running CRC remainder as internal
+/// state, so callers can feed it slices as they arrive, for example when receiving data over a
+/// noisy link in pieces rather than as one buffered frame.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::Crc8Hasher;
+///
+/// let mut hasher = Crc8Hasher::new(0xD5);
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let crc = hasher.finalize();
+///
+/// assert_eq!(crc, crc8_rs::fetch_standard_crc8(*b"Hello, World!", 0xD5));
+/// ```
+pub struct Crc8Hasher {
+    table: Crc8Table,
+    crc: u8,
+    refin: bool,
+    refout: bool,
+    xorout: u8,
+}
+
+impl Crc8Hasher {
+    /// Create a new hasher for a given generator `polynomial`, with the running CRC starting at
+    /// zero. It is a short hand for
+    /// [`with_config(Crc8Configuration::new(polynomial))`](Crc8Hasher::with_config).
+    pub fn new(polynomial: u8) -> Self {
+        Self::with_config(Crc8Configuration::new(polynomial))
+    }
+
+    /// Create a new hasher for a given [`Crc8Configuration`], so the hasher can honour a
+    /// standard CRC-8 variant's initial value, input/output reflection and XOR-out constant, not
+    /// just its generator polynomial.
+    pub fn with_config(config: Crc8Configuration) -> Self {
+        Self {
+            table: Crc8Table::new(config.polynomial),
+            crc: config.init,
+            refin: config.refin,
+            refout: config.refout,
+            xorout: config.xorout,
+        }
+    }
+
+    /// Feed a chunk of data into the running CRC.
+    ///
+    /// May be called any number of times with slices of any length; the result is the same as if
+    /// all the chunks passed so far had been concatenated and fed in through one call.
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            let byte = if self.refin { byte.reverse_bits() } else { byte };
+            self.crc = self.table.get(self.crc ^ byte);
+        }
+    }
+
+    /// Feed a [`Buf`] into the running CRC, walking its chunks so non-contiguous buffers can be
+    /// hashed without copying them into one contiguous slice first.
+    pub fn update_buf<B: Buf>(&mut self, buf: &mut B) {
+        while buf.remaining() > 0 {
+            let chunk = buf.chunk();
+            let len = chunk.len();
+            self.update(chunk);
+            buf.advance(len);
+        }
+    }
+
+    /// Consume the hasher and return the final CRC value.
+    pub fn finalize(self) -> u8 {
+        let crc = if self.refout { self.crc.reverse_bits() } else { self.crc };
+        crc ^ self.xorout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_whole_buffer() {
+        let data = b"Hello World!\0";
+
+        let mut hasher = Crc8Hasher::new(0xD5);
+        hasher.update(data);
+        let chunked = hasher.finalize();
+
+        assert_eq!(chunked, crate::fetch_standard_crc8(*data, 0xD5));
+    }
+
+    #[test]
+    fn chunk_boundaries_do_not_matter() {
+        let data = b"Hello World!\0";
+
+        let mut one_shot = Crc8Hasher::new(0xD5);
+        one_shot.update(data);
+
+        let mut piecewise = Crc8Hasher::new(0xD5);
+        for chunk in data.chunks(3) {
+            piecewise.update(chunk);
+        }
+
+        assert_eq!(one_shot.finalize(), piecewise.finalize());
+    }
+
+    #[test]
+    fn update_buf_walks_chunks() {
+        let data = b"Hello World!\0";
+
+        let mut hasher = Crc8Hasher::new(0xD5);
+        let mut buf: &[u8] = data;
+        hasher.update_buf(&mut buf);
+
+        assert_eq!(hasher.finalize(), crate::fetch_standard_crc8(*data, 0xD5));
+    }
+}
@@ -8,11 +8,26 @@
 //!
 //! # Features
 //!
-//! This crate provides the minimal functions needed to properly handle CRC's in an 8-bit
-//! system. The provided functions are [`fetch_crc8`], [`has_valid_crc8`] and [`insert_crc8`]. This
-//! should make handling most of the common CRC situations simple. Because of the minimalist
-//! approach this crate takes, binary size should remain small. This especially fits well on
-//! embedded hardware.
+//! This crate started out providing the minimal functions needed to properly handle CRC's in an
+//! 8-bit system: [`fetch_crc8`], [`has_valid_crc8`] and [`insert_crc8`], which cover this crate's
+//! own bare-polynomial, zero-init checksum and should make handling most of the common CRC
+//! situations simple. Because of the minimalist approach this crate takes, binary size should
+//! remain small. This especially fits well on embedded hardware.
+//!
+//! It has since grown a few more entry points for when the bare functions above aren't enough:
+//!
+//! - [`Crc8Configuration`] and its `_with_config` counterparts
+//!   ([`fetch_crc8_with_config`], [`has_valid_crc8_with_config`], [`insert_crc8_with_config`]) add
+//!   support for standard CRC-8 parameters (initial value, input/output reflection, XOR-out) on
+//!   top of the bare generator polynomial.
+//! - [`Crc8Variant`] catalogs published CRC-8 standards (e.g. [`Smbus`], [`Bluetooth`]) as their
+//!   own types, and [`Crc8`] wraps a variant into a ready-to-use hasher.
+//! - [`Crc8Hasher`] and [`Buf`] let a CRC be fed incrementally, a chunk at a time, instead of from
+//!   one fully-buffered array.
+//! - [`Crc8Table`] and [`fetch_standard_crc8`] compute the standard, implicit-top-bit CRC-8
+//!   register convention directly, table-driven, without going through a variant or a hasher.
+//! - [`Polynomial`], [`fetch_crc`], [`has_valid_crc`], [`insert_crc`] and [`BarrettReducer`]
+//!   generalize the underlying GF(2) division to an arbitrary-width CRC, beyond 8 bits.
 //!
 //! # Usage
 //!
@@ -156,14 +171,157 @@
 #![warn(missing_docs)]
 #![no_std]
 
-//! The configuration used for a CRC-8 Process
-struct Crc8Configuration {
+mod barrett;
+mod crc;
+mod hasher;
+mod polynomial;
+mod table;
+mod variant;
+
+pub use barrett::BarrettReducer;
+pub use crc::{fetch_crc, has_valid_crc, insert_crc};
+pub use hasher::{Buf, Crc8Hasher};
+pub use polynomial::Polynomial;
+pub use table::{fetch_standard_crc8, Crc8Table};
+pub use variant::{AutosarCrc8H2F, Bluetooth, Cdma2000, Crc8, Crc8Variant, DvbS2, Smbus};
 
+/// The full set of parameters needed to describe a CRC-8 variant.
+///
+/// Real CRC-8 algorithms differ by more than their generator polynomial: they also specify an
+/// initial remainder value, whether input bytes are bit-reflected (processed LSB-first) before
+/// being fed in, whether the final remainder is bit-reflected, and a final XOR constant. This
+/// type bundles all five, so the crate can match standard register-transfer CRC-8 definitions
+/// instead of only its own bare-polynomial, zero-init variant.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::Crc8Configuration;
+///
+/// // `Crc8Configuration::new` reproduces the behaviour of the bare `fetch_crc8`/`insert_crc8`
+/// // functions: no initial value, no reflection, no XOR-out.
+/// let config = Crc8Configuration::new(0xD5);
+/// assert_eq!(config.init, 0x00);
+/// assert!(!config.refin);
+/// assert!(!config.refout);
+/// assert_eq!(config.xorout, 0x00);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crc8Configuration {
+    /// The generator polynomial.
+    pub polynomial: u8,
+    /// The value the CRC register is initialized with before any data is processed.
+    pub init: u8,
+    /// Whether each input byte is bit-reflected (processed LSB-first) before being fed in.
+    pub refin: bool,
+    /// Whether the final CRC value is bit-reflected before the `xorout` step.
+    pub refout: bool,
+    /// The value XORed into the final, possibly reflected, CRC value.
+    pub xorout: u8,
 }
 
-mod polynomial;
+impl Crc8Configuration {
+    /// Build the configuration the bare-polynomial functions in this crate use: `init = 0`, no
+    /// reflection and `xorout = 0`.
+    pub const fn new(polynomial: u8) -> Self {
+        Self {
+            polynomial,
+            init: 0x00,
+            refin: false,
+            refout: false,
+            xorout: 0x00,
+        }
+    }
+}
+
+/// Get the current CRC of a `data` buffer under a given `config`uration.
+///
+/// This is the generalized form of [`fetch_crc8`] that additionally honours `config`'s initial
+/// value, input/output reflection and XOR-out constant, which lets the crate match standard
+/// register-transfer CRC-8 definitions rather than only its own MSB-first, zero-init variant.
+///
+/// For the bare-polynomial default configuration (`init = 0`, no reflection, `xorout = 0`), this
+/// reproduces `fetch_crc8`'s original, long-shipped bare long division exactly, rather than going
+/// through the byte-wise [`Crc8Hasher`] engine below, which implements the standard,
+/// implicit-top-bit CRC convention - a different algorithm that does not agree with it.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::{ fetch_crc8, fetch_crc8_with_config, Crc8Configuration };
+///
+/// let data = [0x02, 0x30, 0xf0, 0x00];
+///
+/// // With the default configuration this matches the bare `fetch_crc8`.
+/// assert_eq!(fetch_crc8(data, 0xA6), fetch_crc8_with_config(data, Crc8Configuration::new(0xA6)));
+/// ```
+///
+/// # Panics
+///
+/// This function will panic when given a zero-sized buffer.
+pub fn fetch_crc8_with_config<const DATA_SIZE: usize>(
+    data: [u8; DATA_SIZE],
+    config: Crc8Configuration,
+) -> u8 {
+    assert!(DATA_SIZE > 0, "data buffer must not be empty");
+
+    if config == Crc8Configuration::new(config.polynomial) {
+        let generator = Polynomial::<DATA_SIZE>::new_from_bytes([config.polynomial]);
+        let Polynomial(remainder) = Polynomial(data).legacy_div(generator);
+        return remainder[DATA_SIZE - 1];
+    }
+
+    let mut hasher = Crc8Hasher::with_config(config);
+    hasher.update(&data);
+    hasher.finalize()
+}
+
+/// Determine whether a `data` buffer has a valid CRC under a given `config`uration.
+///
+/// This is the generalized form of [`has_valid_crc8`]: it is a short hand for
+/// [`fetch_crc8_with_config(data, config) == config.xorout`](fetch_crc8_with_config).
+///
+/// # Panics
+///
+/// This function will panic when given a zero-sized buffer.
+pub fn has_valid_crc8_with_config<const DATA_SIZE: usize>(
+    data: [u8; DATA_SIZE],
+    config: Crc8Configuration,
+) -> bool {
+    fetch_crc8_with_config(data, config) == config.xorout
+}
+
+/// Insert the CRC byte in the last byte of `data` buffer under a given `config`uration.
+///
+/// This is the generalized form of [`insert_crc8`]. For the bare-polynomial default configuration
+/// this is the same closed-form `polynomial ^ fetch_crc8(..)` XOR `insert_crc8` always used, which
+/// avoids a division per candidate byte. Unlike that case, a nonzero `init`, reflection or `xorout`
+/// does not reduce to a simple closed-form XOR of the last byte, so this searches the 256 possible
+/// last-byte values for the one that brings the buffer back to a valid CRC under `config`.
+///
+/// # Panics
+///
+/// This function will panic when given a zero-sized buffer.
+pub fn insert_crc8_with_config<const DATA_SIZE: usize>(
+    mut data: [u8; DATA_SIZE],
+    config: Crc8Configuration,
+) -> [u8; DATA_SIZE] {
+    if config == Crc8Configuration::new(config.polynomial) {
+        data[DATA_SIZE - 1] = 0x00;
+        data[DATA_SIZE - 1] = config.polynomial ^ fetch_crc8_with_config(data, config);
+        return data;
+    }
 
-use polynomial::Polynomial;
+    for candidate in 0..=u8::MAX {
+        data[DATA_SIZE - 1] = candidate;
+
+        if has_valid_crc8_with_config(data, config) {
+            return data;
+        }
+    }
+
+    data
+}
 
 /// Determine whether a `data` buffer for a given generator `polynomial` has a valid CRC.
 ///
@@ -205,17 +363,17 @@ use polynomial::Polynomial;
 /// has_valid_crc8([], 0x42);
 /// ```
 pub fn has_valid_crc8<const DATA_SIZE: usize>(data: [u8; DATA_SIZE], polynomial: u8) -> bool {
-    fetch_crc8(data, polynomial) == 0
+    has_valid_crc8_with_config(data, Crc8Configuration::new(polynomial))
 }
 
 /// Get the current CRC of a `data` buffer under a generator `polynomial`.
 ///
-/// Calculates the polynomial modulo division of the `data` buffer with the `polynomial`. If we
-/// give a valid CRC appended `data` buffer under `polynomial`, we will get `0` back. The
-/// short-hand of this is the [`has_valid_crc8`] function. When given a null terminated `data`
-/// buffer, the `fetch_crc8(data, polynomial) ^ polynomial` will equal the value needed to be set
-/// as the last byte in order to get a valid CRC signed buffer. The short-hand of this is the
-/// [`insert_crc8`] function.
+/// Calculates the CRC-8 of the `data` buffer with the `polynomial`, with no initial value, input
+/// or output reflection, and no XOR-out constant. It is a short hand for
+/// [`fetch_crc8_with_config(data, Crc8Configuration::new(polynomial))`](fetch_crc8_with_config).
+/// If we give a valid CRC appended `data` buffer under `polynomial`, we will get `0` back, which
+/// is what [`has_valid_crc8`] checks for. [`insert_crc8`] computes the byte that needs to be set
+/// as the last byte in order to get a valid CRC signed buffer.
 ///
 /// # Examples
 ///
@@ -261,18 +419,14 @@ pub fn has_valid_crc8<const DATA_SIZE: usize>(data: [u8; DATA_SIZE], polynomial:
 /// fetch_crc8([], 0x42);
 /// ```
 pub fn fetch_crc8<const DATA_SIZE: usize>(data: [u8; DATA_SIZE], polynomial: u8) -> u8 {
-    // Fetch the modulo division of the data with the generator polynomial
-    let Polynomial(result_arr) = Polynomial(data) / Polynomial::new_from_byte(polynomial);
-
-    // Then return the last byte
-    result_arr[DATA_SIZE - 1]
+    fetch_crc8_with_config(data, Crc8Configuration::new(polynomial))
 }
 
 /// Insert CRC byte in the last byte of `data` buffer under a generator `polynomial`.
 ///
 /// This expects a last byte left for the CRC byte, any pre-existing last byte value will be
-/// ignored and overwritten in the return value. This function is very similar to writing
-/// [`data[data.len() - 1] = polynomial ^ fetch_crc8(data, polynomial)`](fetch_crc8).
+/// ignored and overwritten in the return value. It is a short hand for
+/// [`insert_crc8_with_config(data, Crc8Configuration::new(polynomial))`](insert_crc8_with_config).
 ///
 /// # Examples
 ///
@@ -309,15 +463,10 @@ pub fn fetch_crc8<const DATA_SIZE: usize>(data: [u8; DATA_SIZE], polynomial: u8)
 /// insert_crc8([], 0x42);
 /// ```
 pub fn insert_crc8<const DATA_SIZE: usize>(
-    mut data: [u8; DATA_SIZE],
+    data: [u8; DATA_SIZE],
     polynomial: u8,
 ) -> [u8; DATA_SIZE] {
-    // Set the CRC byte to zero.
-    data[DATA_SIZE - 1] = 0x00;
-
-    // Fetch the crc and write to the last byte the byte which turns the crc into zero.
-    data[DATA_SIZE - 1] = polynomial ^ fetch_crc8(data, polynomial);
-    data
+    insert_crc8_with_config(data, Crc8Configuration::new(polynomial))
 }
 
 #[test]
@@ -336,3 +485,25 @@ fn crc_cycle() {
         assert!(has_valid_crc8(insert_crc8(test_vector, 0xA6), 0xA6));
     }
 }
+
+#[test]
+fn insert_crc8_with_config_round_trips_for_a_non_default_config() {
+    // A non-default configuration (nonzero init, input/output reflection), so this exercises the
+    // brute-force candidate search in `insert_crc8_with_config`, not the default config's closed
+    // form.
+    let config = Crc8Configuration {
+        polynomial: 0xA7,
+        init: 0xFF,
+        refin: true,
+        refout: true,
+        xorout: 0x00,
+    };
+
+    let data = [0x02, 0x30, 0xf0, 0x00];
+    let signed = insert_crc8_with_config(data, config);
+    assert!(has_valid_crc8_with_config(signed, config));
+
+    let mut corrupted = signed;
+    corrupted[1] ^= 0xff;
+    assert!(!has_valid_crc8_with_config(corrupted, config));
+}
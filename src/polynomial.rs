@@ -1,6 +1,8 @@
-use core::ops::{Div, Shl, Sub};
+use core::ops::{Div, Shl, Shr, Sub};
 
-/// Corresponding to the Finite Field Polynomials
+/// A GF(2) polynomial, represented as `MAX_BYTES` coefficient bytes, most significant byte first.
+///
+/// This is the bare-polynomial long division core [`fetch_crc`](crate::fetch_crc) is built on.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Polynomial<const MAX_BYTES: usize>(pub [u8; MAX_BYTES]);
 
@@ -28,9 +30,32 @@ impl<const MAX_BYTES: usize> Polynomial<MAX_BYTES> {
         Polynomial(arr)
     }
 
+    /// Shift all the bytes contained in the polynomial over by n bytes to the right
+    fn rotate_right(&self, n: usize) -> Self {
+        // Check that the n isn't bigger than the amount of bytes in the polynomial
+        // If so, return a empty polynomial
+        if n >= MAX_BYTES {
+            return Polynomial([0; MAX_BYTES]);
+        }
+
+        let Polynomial(mut arr) = self;
+
+        // Move all bytes over by n indices
+        for i in (n..MAX_BYTES).rev() {
+            arr[i] = arr[i - n];
+        }
+
+        // Set all leftover bytes to zero
+        for i in 0..n {
+            arr[i] = 0x00;
+        }
+
+        Polynomial(arr)
+    }
+
     /// Fetch the index of the most significant non-zero bit. Starting from 0 for the least
     /// significant bit.
-    fn bit_len(&self) -> usize {
+    pub(crate) fn bit_len(&self) -> usize {
         let Polynomial(arr) = self;
 
         // Loop through all bytes (Most sign. to least sign.)
@@ -48,48 +73,101 @@ impl<const MAX_BYTES: usize> Polynomial<MAX_BYTES> {
         0
     }
 
-    /// Return whether `self` is is 'greater' than `cmp`, meaning that `self` has the highest
-    /// significant bit which `cmp` does not have.
+    /// Return whether `self` is 'greater' than `cmp`, meaning that `self` has a strictly higher
+    /// polynomial degree than `cmp`. This is a degree comparison, not a magnitude one: two
+    /// polynomials of equal degree are never "more significant" than one another, regardless of
+    /// which one is numerically larger, since `Div` must keep reducing until the remainder's
+    /// degree drops below the divisor's.
+    ///
+    /// `bit_len` reports 0 for both the zero polynomial and a degree-0 one (e.g. the constant
+    /// `1`), since it has no representation for "no bits at all". Handle the zero case
+    /// explicitly here instead, so a zero `cmp` is always treated as lower-degree than any
+    /// non-zero `self`, and `Div` can still recognize a fully reduced, exactly-zero remainder.
     fn is_more_sign(&self, cmp: Self) -> bool {
-        let Polynomial(self_arr) = self;
-        let Polynomial(cmp_arr) = cmp;
+        let zero = Self([0x00; MAX_BYTES]);
 
-        // Loop through all bytes (Most sign. to least sign.)
-        for byte_index in 0..MAX_BYTES {
-            let self_byte = self_arr[byte_index];
-            let cmp_byte = cmp_arr[byte_index];
-
-            // Shortcut for if both bytes are the same.
-            if self_byte == cmp_byte {
-                continue;
-            }
-
-            // Loop through all bits (Most sign. to least sign.)
-            for bit_index in (0..8).rev() {
-                let self_has_bit = self_byte & (0x01 << bit_index) != 0;
-                let cmp_has_bit = cmp_byte & (0x01 << bit_index) != 0;
+        if cmp == zero {
+            return *self != zero;
+        }
 
-                // If both bits are the same continue to the next bit.
-                if self_has_bit == cmp_has_bit {
-                    continue;
-                }
+        self.bit_len() > cmp.bit_len()
+    }
 
-                // If the self then has a bit, we know that cmp does not have a bit.
-                // Thus we know that self is more significant.
-                // This also holds the other way round.
-                return self_has_bit;
-            }
-        }
+    /// Build a polynomial whose low `N` bytes are `bytes`, and whose remaining, more significant
+    /// bytes are zero.
+    ///
+    /// This generalizes embedding a single generator byte into a same-sized [`Polynomial`] for
+    /// division, to a generator of any width `N`, which is what lets [`fetch_crc`](crate::fetch_crc)
+    /// support generators wider than one byte.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `N` is bigger than `MAX_BYTES`.
+    pub fn new_from_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        assert!(N <= MAX_BYTES, "generator is wider than the polynomial it is embedded in");
 
-        false
+        let mut arr = [0x00; MAX_BYTES];
+        arr[(MAX_BYTES - N)..].copy_from_slice(&bytes);
+        Polynomial(arr)
     }
 
-    /// Create a polynomial array with the poly byte at the first place.
-    pub fn new_from_byte(byte: u8) -> Self {
+    /// Build the monomial `x^degree`, i.e. the polynomial with only bit `degree` set.
+    ///
+    /// This is used by [`BarrettReducer`](crate::BarrettReducer) to build the `x^(2k)` value its
+    /// reciprocal constant is derived from.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `degree` does not fit in `MAX_BYTES` bytes.
+    pub fn monomial(degree: usize) -> Self {
+        assert!(degree < MAX_BYTES * 8, "degree does not fit in this polynomial's width");
+
         let mut arr = [0x00; MAX_BYTES];
-        arr[MAX_BYTES - 1] = byte;
+        arr[MAX_BYTES - 1 - degree / 8] = 0x01 << (degree % 8);
         Polynomial(arr)
     }
+
+    /// Narrow this polynomial to its low `N` bytes, discarding the more significant bytes.
+    ///
+    /// This is the inverse of [`new_from_bytes`](Self::new_from_bytes), used by
+    /// [`BarrettReducer`](crate::BarrettReducer) to pull a fixed-width result back out of a wider
+    /// intermediate value.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `N` is bigger than `MAX_BYTES`.
+    pub fn truncate<const N: usize>(&self) -> Polynomial<N> {
+        assert!(N <= MAX_BYTES, "cannot truncate a polynomial to a wider one");
+
+        let Polynomial(arr) = self;
+        let mut out = [0x00; N];
+        out.copy_from_slice(&arr[(MAX_BYTES - N)..]);
+        Polynomial(out)
+    }
+
+    /// Carry-less multiplication of `self` by `rhs`, i.e. multiplication in `GF(2)[x]`, truncated
+    /// (or zero-extended) to fit in `OUT_BYTES` bytes.
+    ///
+    /// This is the building block [`BarrettReducer`](crate::BarrettReducer) uses in place of the
+    /// shift-and-subtract loop [`Div`] performs, since a carry-less multiply takes a fixed number of
+    /// shift-and-XOR steps instead of a data-dependent one.
+    pub fn clmul<const RHS_BYTES: usize, const OUT_BYTES: usize>(
+        &self,
+        rhs: Polynomial<RHS_BYTES>,
+    ) -> Polynomial<OUT_BYTES> {
+        let Polynomial(rhs_arr) = rhs;
+        let widened: Polynomial<OUT_BYTES> = Polynomial::new_from_bytes(self.0);
+
+        let mut acc = Polynomial([0x00; OUT_BYTES]);
+        for bit in 0..(RHS_BYTES * 8) {
+            let byte = rhs_arr[RHS_BYTES - 1 - bit / 8];
+            if byte & (0x01 << (bit % 8)) != 0 {
+                acc = acc - (widened << bit);
+            }
+        }
+
+        acc
+    }
 }
 
 #[cfg(test)]
@@ -109,6 +187,19 @@ mod impls {
         rl_tv!([0, 1, 0, 2], 4 => [0, 0, 0, 0]);
     }
 
+    #[test]
+    fn rotate_right() {
+        macro_rules! rr_tv {
+            ($bytes:expr, $amount:expr => $ans:expr) => {
+                assert_eq!(Polynomial($bytes).rotate_right($amount), Polynomial($ans));
+            };
+        }
+
+        rr_tv!([1, 0, 2, 0], 1 => [0, 1, 0, 2]);
+        rr_tv!([2, 0, 0, 0], 3 => [0, 0, 0, 2]);
+        rr_tv!([0, 1, 0, 2], 4 => [0, 0, 0, 0]);
+    }
+
     #[test]
     fn bit_len() {
         macro_rules! bl_tv {
@@ -138,6 +229,40 @@ mod impls {
         ms_tv!([0x80, 0x00], [0x00, 0x07]);
         ms_tv!(> [0x00, 0x07], [0x80, 0x00]);
     }
+
+    #[test]
+    fn new_from_bytes() {
+        assert_eq!(
+            Polynomial::<4>::new_from_bytes([0xab, 0xcd]),
+            Polynomial([0x00, 0x00, 0xab, 0xcd])
+        );
+        assert_eq!(Polynomial::<4>::new_from_bytes([0x07]), Polynomial([0x00, 0x00, 0x00, 0x07]));
+    }
+
+    #[test]
+    fn monomial() {
+        assert_eq!(Polynomial::<2>::monomial(0), Polynomial([0x00, 0x01]));
+        assert_eq!(Polynomial::<2>::monomial(8), Polynomial([0x01, 0x00]));
+        assert_eq!(Polynomial::<2>::monomial(15), Polynomial([0x80, 0x00]));
+    }
+
+    #[test]
+    fn truncate() {
+        let wide = Polynomial([0x00, 0x00, 0xab, 0xcd]);
+        assert_eq!(wide.truncate(), Polynomial([0xab, 0xcd]));
+        assert_eq!(wide.truncate(), Polynomial([0x00, 0xab, 0xcd]));
+    }
+
+    #[test]
+    fn clmul() {
+        // (x^3 + x) * (x + 1) = x^4 + x^3 + x^2 + x, i.e. 0b1010 * 0b11 = 0b11110
+        let a = Polynomial([0x0a]);
+        let b = Polynomial([0x03]);
+        assert_eq!(a.clmul(b), Polynomial([0x00, 0x1e]));
+
+        // The product fits in a single byte, so a one-byte output holds all of it.
+        assert_eq!(a.clmul::<1, 1>(b), Polynomial([0x1e]));
+    }
 }
 
 impl<const MAX_BYTES: usize> Shl<usize> for Polynomial<MAX_BYTES> {
@@ -180,6 +305,43 @@ fn shift_left() {
     shl_tv!([0xcc, 0x33], 2 => [0x30, 0xcc]);
 }
 
+impl<const MAX_BYTES: usize> Shr<usize> for Polynomial<MAX_BYTES> {
+    type Output = Self;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        // TLDR: Mirror image of Shl: rotate whole bytes first, then shift the remaining bits,
+        // carrying in from the more significant neighbouring byte.
+
+        let Polynomial(mut rotated) = self.rotate_right(rhs / 8);
+        let shr_amount = rhs % 8;
+
+        if shr_amount != 0 {
+            for i in (1..MAX_BYTES).rev() {
+                rotated[i] = (rotated[i] >> shr_amount) |          // ABCD EFGH => 000A BCDE
+                             (rotated[i - 1] << (8 - shr_amount)); // ABCD EFGH => FGH0 0000
+            }
+
+            // Shift the first byte
+            rotated[0] >>= shr_amount;
+        }
+
+        Polynomial(rotated)
+    }
+}
+
+#[test]
+fn shift_right() {
+    macro_rules! shr_tv {
+        ($bytes:expr, $shr:expr => $ans:expr) => {
+            assert_eq!(Polynomial($bytes) >> $shr, Polynomial($ans));
+        };
+    }
+
+    shr_tv!([0xab, 0x00], 8 => [0x00, 0xab]);
+    shr_tv!([0x0a, 0xb0], 4 => [0x00, 0xab]);
+    shr_tv!([0x30, 0xcc], 2 => [0x0c, 0x33]);
+}
+
 impl<const MAX_BYTES: usize> Sub for Polynomial<MAX_BYTES> {
     type Output = Self;
 
@@ -222,6 +384,54 @@ impl<const MAX_BYTES: usize> Div for Polynomial<MAX_BYTES> {
     }
 }
 
+impl<const MAX_BYTES: usize> Polynomial<MAX_BYTES> {
+    /// Divide `self` by `rhs` the way this crate originally did, before `is_more_sign` was fixed
+    /// to compare polynomials by degree instead of raw magnitude.
+    ///
+    /// [`Div`](core::ops::Div) now computes the mathematically correct remainder, which disagrees
+    /// with this on a sizeable fraction of inputs whenever the remainder and divisor tie in bit
+    /// length but differ in magnitude. `fetch_crc8`/`insert_crc8`/`has_valid_crc8` shipped for a
+    /// long time computing the old, not-quite-correct remainder, so they keep using this for their
+    /// bare-polynomial default configuration instead of `Div`, to avoid silently invalidating
+    /// already-CRC-tagged buffers. New code should use `Div` (or [`Polynomial::new_from_bytes`]
+    /// plus the generalized [`fetch_crc`](crate::fetch_crc) family), which does not carry this
+    /// legacy quirk.
+    pub(crate) fn legacy_div(mut self, rhs: Self) -> Self {
+        fn is_more_sign_by_magnitude<const N: usize>(lhs: &Polynomial<N>, rhs: &Polynomial<N>) -> bool {
+            let Polynomial(lhs_arr) = lhs;
+            let Polynomial(rhs_arr) = rhs;
+
+            for byte_index in 0..N {
+                let lhs_byte = lhs_arr[byte_index];
+                let rhs_byte = rhs_arr[byte_index];
+
+                if lhs_byte == rhs_byte {
+                    continue;
+                }
+
+                for bit_index in (0..8).rev() {
+                    let lhs_has_bit = lhs_byte & (0x01 << bit_index) != 0;
+                    let rhs_has_bit = rhs_byte & (0x01 << bit_index) != 0;
+
+                    if lhs_has_bit == rhs_has_bit {
+                        continue;
+                    }
+
+                    return lhs_has_bit;
+                }
+            }
+
+            false
+        }
+
+        while !is_more_sign_by_magnitude(&rhs, &self) {
+            self = self - (rhs << (self.bit_len() - rhs.bit_len()));
+        }
+
+        self
+    }
+}
+
 #[test]
 fn div_test_vectors() {
     macro_rules! div_tv {
@@ -238,7 +448,11 @@ fn div_test_vectors() {
 
     assert_eq!(Polynomial(denum) << 5, Polynomial([0x23, 0x60]));
 
-    div_tv!(num, denum => [0x01, 0x1a]);
+    // Note this remainder is [0x00, 0x01], not [0x01, 0x1a]: `Div` used to stop one subtraction
+    // step early whenever the running remainder and divisor tied in bit length, because
+    // `is_more_sign` compared by raw magnitude instead of by degree. Fixed, `Div` keeps reducing
+    // through the tie, matching true GF(2) polynomial division.
+    div_tv!(num, denum => [0x00, 0x01]);
 }
 
 #[cfg(test)]
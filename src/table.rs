@@ -0,0 +1,127 @@
+/// A precomputed lookup table for table-driven CRC-8 computation.
+///
+/// Building this table once and then looking up a single entry per input byte turns a bit-by-bit
+/// long division into one array index per byte, which is a large speedup for the packet-buffer
+/// sizes this crate targets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crc8Table([u8; 256]);
+
+impl Crc8Table {
+    /// Generate the lookup table for a given generator `polynomial`.
+    ///
+    /// This is a `const fn`, so the table can be computed at compile time and placed in
+    /// flash/rodata on embedded targets instead of being rebuilt at startup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crc8_rs::Crc8Table;
+    ///
+    /// const TABLE: Crc8Table = Crc8Table::new(0xD5);
+    /// ```
+    pub const fn new(polynomial: u8) -> Self {
+        let mut table = [0u8; 256];
+
+        let mut i = 0;
+        while i < 256 {
+            let mut crc = i as u8;
+
+            let mut bit = 0;
+            while bit < 8 {
+                if crc & 0x80 != 0 {
+                    crc = (crc << 1) ^ polynomial;
+                } else {
+                    crc <<= 1;
+                }
+
+                bit += 1;
+            }
+
+            table[i] = crc;
+            i += 1;
+        }
+
+        Self(table)
+    }
+
+    /// Look up the next CRC state for the current running `state` byte.
+    pub const fn get(&self, state: u8) -> u8 {
+        self.0[state as usize]
+    }
+}
+
+/// Get the current CRC of a `data` buffer under a generator `polynomial`, using the standard,
+/// implicit-top-bit CRC-8 register convention and a table-driven engine that processes one input
+/// byte per table lookup.
+///
+/// This is a much faster alternative to a bit-by-bit long division for the packet-buffer use
+/// cases this crate targets, running in `O(DATA_SIZE)` table lookups instead.
+///
+/// **This is not a faster [`fetch_crc8`](crate::fetch_crc8) and the two are not drop-in
+/// replacements for one another** - the name describes the engine (table-driven vs. bit-by-bit),
+/// not a shared result. This function treats `polynomial` as the low 8 bits of a degree-8
+/// generator with an implicit leading `1` bit (the standard CRC-8 register convention, the one
+/// that produces published check values such as CRC-8/SMBUS's `0xF4` for `b"123456789"` with
+/// `polynomial = 0x07`). `fetch_crc8` instead divides by `polynomial` taken completely literally,
+/// with no implicit bit, so its effective generator degree is `polynomial`'s own highest set bit -
+/// for example degree 2 for the same `0x07`, not 8. These are reductions modulo two different
+/// polynomials, so for almost any input they disagree; for instance `fetch_crc8([0x02, 0x30, 0xf0,
+/// 0x00], 0xA6)` is `0x08`, while `fetch_standard_crc8([0x02, 0x30, 0xf0, 0x00], 0xA6)` is `0xb0`.
+/// Use this function when you want a standard CRC-8 matching a published variant (see
+/// [`Crc8Variant`](crate::Crc8Variant)); use [`fetch_crc8`](crate::fetch_crc8) when you need this
+/// crate's own bare-polynomial checksum.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::fetch_standard_crc8;
+///
+/// // CRC-8/SMBUS uses polynomial 0x07 and has a published check value of 0xF4 for the ASCII
+/// // string "123456789".
+/// assert_eq!(fetch_standard_crc8(*b"123456789", 0x07), 0xF4);
+/// ```
+pub fn fetch_standard_crc8<const DATA_SIZE: usize>(data: [u8; DATA_SIZE], polynomial: u8) -> u8 {
+    let table = Crc8Table::new(polynomial);
+
+    let mut crc = 0u8;
+    for &byte in data.iter() {
+        crc = table.get(crc ^ byte);
+    }
+
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smbus_check_value() {
+        // CRC-8/SMBUS: poly=0x07, init=0x00, no reflection, xorout=0x00, check=0xF4.
+        assert_eq!(fetch_standard_crc8(*b"123456789", 0x07), 0xF4);
+    }
+
+    #[test]
+    fn table_is_reused_across_lookups() {
+        let table = Crc8Table::new(0x07);
+
+        let mut crc = 0u8;
+        for &byte in b"123456789" {
+            crc = table.get(crc ^ byte);
+        }
+
+        assert_eq!(crc, 0xF4);
+    }
+
+    #[test]
+    fn differs_from_bare_long_division() {
+        // Pinned so this known, permanent divergence (see `fetch_standard_crc8`'s doc comment)
+        // doesn't get "fixed" back towards matching `fetch_crc8` by accident: the two compute
+        // remainders modulo two different polynomials (degree 8 with an implicit leading bit here,
+        // vs. `polynomial`'s own literal degree in `fetch_crc8`), so they are not interchangeable.
+        let data = [0x02, 0x30, 0xf0, 0x00];
+
+        assert_eq!(crate::fetch_crc8(data, 0xA6), 0x08);
+        assert_eq!(fetch_standard_crc8(data, 0xA6), 0xb0);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Crc8Configuration, Crc8Hasher, Crc8Table};
+
+/// A marker type describing a standard, named CRC-8 variant.
+///
+/// Implementors supply the generator polynomial, initial value, reflection flags and XOR-out
+/// constant for a published CRC-8 standard, plus a `CHECK` constant: the CRC of the ASCII string
+/// `"123456789"`, which is the conventional self-test value used to confirm an implementation
+/// matches the standard. [`Crc8<V>`](crate::Crc8) uses this to build a ready-to-use hasher
+/// without the generator's magic bytes being passed around by hand.
+pub trait Crc8Variant {
+    /// The generator polynomial.
+    const POLYNOMIAL: u8;
+    /// The value the CRC register is initialized with before any data is processed.
+    const INIT: u8;
+    /// Whether each input byte is bit-reflected (processed LSB-first) before being fed in.
+    const REFIN: bool;
+    /// Whether the final CRC value is bit-reflected before the `xorout` step.
+    const REFOUT: bool;
+    /// The value XORed into the final, possibly reflected, CRC value.
+    const XOROUT: u8;
+    /// The CRC of the ASCII string `"123456789"` under this variant, as published by the
+    /// standard.
+    const CHECK: u8;
+
+    /// Build the [`Crc8Configuration`] this variant describes.
+    fn configuration() -> Crc8Configuration {
+        Crc8Configuration {
+            polynomial: Self::POLYNOMIAL,
+            init: Self::INIT,
+            refin: Self::REFIN,
+            refout: Self::REFOUT,
+            xorout: Self::XOROUT,
+        }
+    }
+}
+
+/// Compute the CRC of the ASCII string `"123456789"` for a given set of parameters.
+///
+/// This is only used at compile time, to self-test that each [`Crc8Variant`] impl's `CHECK`
+/// constant matches what its other parameters actually produce, catching misconfigured variants
+/// before they ever run.
+const fn const_check(polynomial: u8, init: u8, refin: bool, refout: bool, xorout: u8) -> u8 {
+    let table = Crc8Table::new(polynomial);
+    let data = b"123456789";
+
+    let mut crc = init;
+    let mut i = 0;
+    while i < data.len() {
+        let byte = if refin { data[i].reverse_bits() } else { data[i] };
+        crc = table.get(crc ^ byte);
+        i += 1;
+    }
+
+    if refout {
+        crc = crc.reverse_bits();
+    }
+
+    crc ^ xorout
+}
+
+/// A CRC-8 hasher for a specific, named [`Crc8Variant`].
+///
+/// This pairs [`Crc8Hasher`] with a variant marker type `V`, so the generator polynomial, initial
+/// value, reflection flags and XOR-out constant never need to be passed around by hand.
+///
+/// # Examples
+///
+/// ```
+/// use crc8_rs::{Crc8, Crc8Variant, Smbus};
+///
+/// let mut crc = Crc8::<Smbus>::new();
+/// crc.update(b"123456789");
+/// assert_eq!(crc.finalize(), Smbus::CHECK);
+/// ```
+pub struct Crc8<V: Crc8Variant> {
+    hasher: Crc8Hasher,
+    _variant: core::marker::PhantomData<V>,
+}
+
+impl<V: Crc8Variant> Crc8<V> {
+    /// Create a new hasher for the variant `V`.
+    pub fn new() -> Self {
+        Self {
+            hasher: Crc8Hasher::with_config(V::configuration()),
+            _variant: core::marker::PhantomData,
+        }
+    }
+
+    /// Feed a chunk of data into the running CRC.
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// Consume the hasher and return the final CRC value.
+    pub fn finalize(self) -> u8 {
+        self.hasher.finalize()
+    }
+}
+
+impl<V: Crc8Variant> Default for Crc8<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The `CRC-8/SMBUS` variant, as used by the System Management Bus specification.
+pub struct Smbus;
+
+impl Crc8Variant for Smbus {
+    const POLYNOMIAL: u8 = 0x07;
+    const INIT: u8 = 0x00;
+    const REFIN: bool = false;
+    const REFOUT: bool = false;
+    const XOROUT: u8 = 0x00;
+    const CHECK: u8 = 0xF4;
+}
+
+const _: () = assert!(
+    const_check(
+        Smbus::POLYNOMIAL,
+        Smbus::INIT,
+        Smbus::REFIN,
+        Smbus::REFOUT,
+        Smbus::XOROUT
+    ) == Smbus::CHECK
+);
+
+/// The `CRC-8/BLUETOOTH` variant, as used in the Bluetooth packet header.
+pub struct Bluetooth;
+
+impl Crc8Variant for Bluetooth {
+    const POLYNOMIAL: u8 = 0xA7;
+    const INIT: u8 = 0x00;
+    const REFIN: bool = true;
+    const REFOUT: bool = true;
+    const XOROUT: u8 = 0x00;
+    const CHECK: u8 = 0x26;
+}
+
+const _: () = assert!(
+    const_check(
+        Bluetooth::POLYNOMIAL,
+        Bluetooth::INIT,
+        Bluetooth::REFIN,
+        Bluetooth::REFOUT,
+        Bluetooth::XOROUT
+    ) == Bluetooth::CHECK
+);
+
+/// The `CRC-8/CDMA2000` variant, as used in CDMA2000 air-interface radio links.
+pub struct Cdma2000;
+
+impl Crc8Variant for Cdma2000 {
+    const POLYNOMIAL: u8 = 0x9B;
+    const INIT: u8 = 0xFF;
+    const REFIN: bool = false;
+    const REFOUT: bool = false;
+    const XOROUT: u8 = 0x00;
+    const CHECK: u8 = 0xDA;
+}
+
+const _: () = assert!(
+    const_check(
+        Cdma2000::POLYNOMIAL,
+        Cdma2000::INIT,
+        Cdma2000::REFIN,
+        Cdma2000::REFOUT,
+        Cdma2000::XOROUT
+    ) == Cdma2000::CHECK
+);
+
+/// The `CRC-8/DVB-S2` variant, as used in DVB-S2 satellite broadcast framing.
+pub struct DvbS2;
+
+impl Crc8Variant for DvbS2 {
+    const POLYNOMIAL: u8 = 0xD5;
+    const INIT: u8 = 0x00;
+    const REFIN: bool = false;
+    const REFOUT: bool = false;
+    const XOROUT: u8 = 0x00;
+    const CHECK: u8 = 0xBC;
+}
+
+const _: () = assert!(
+    const_check(
+        DvbS2::POLYNOMIAL,
+        DvbS2::INIT,
+        DvbS2::REFIN,
+        DvbS2::REFOUT,
+        DvbS2::XOROUT
+    ) == DvbS2::CHECK
+);
+
+/// The `CRC-8/AUTOSAR` variant (AUTOSAR's `H2F` polynomial), as used by the AUTOSAR automotive
+/// software platform.
+pub struct AutosarCrc8H2F;
+
+impl Crc8Variant for AutosarCrc8H2F {
+    const POLYNOMIAL: u8 = 0x2F;
+    const INIT: u8 = 0xFF;
+    const REFIN: bool = false;
+    const REFOUT: bool = false;
+    const XOROUT: u8 = 0xFF;
+    const CHECK: u8 = 0xDF;
+}
+
+const _: () = assert!(
+    const_check(
+        AutosarCrc8H2F::POLYNOMIAL,
+        AutosarCrc8H2F::INIT,
+        AutosarCrc8H2F::REFIN,
+        AutosarCrc8H2F::REFOUT,
+        AutosarCrc8H2F::XOROUT
+    ) == AutosarCrc8H2F::CHECK
+);